@@ -0,0 +1,133 @@
+//! Soundness-testing helpers shared across the gadgets in this crate.
+//!
+//! `MockProver::verify()` already returns a `Vec<VerifyFailure>` describing
+//! exactly which gate/lookup/permutation failed and where. The helpers below
+//! turn that into assertions so a negative test can pin down *which* failure
+//! it expected instead of just checking `prover.verify().is_err()`.
+
+use halo2_proofs::dev::{FailureLocation, VerifyFailure};
+
+/// Runs a circuit that is expected to fail verification and returns the
+/// failures for further inspection, panicking if verification unexpectedly
+/// succeeds.
+pub fn run_mock_prover<F, ConcreteCircuit>(
+    k: u32,
+    circuit: &ConcreteCircuit,
+    instance: Vec<Vec<F>>,
+) -> Vec<VerifyFailure>
+where
+    F: halo2_proofs::arithmetic::FieldExt,
+    ConcreteCircuit: halo2_proofs::plonk::Circuit<F>,
+{
+    halo2_proofs::dev::MockProver::run(k, circuit, instance)
+        .expect("MockProver::run failed to synthesize the circuit")
+        .verify()
+        .expect_err("expected circuit to fail verification, but it passed")
+}
+
+/// Asserts that `failures` contains a `ConstraintNotSatisfied` failure for
+/// the gate named `gate_name` at the given row within the region it was
+/// assigned in.
+pub fn assert_constraint_fails(failures: &[VerifyFailure], gate_name: &str, row: usize) {
+    let found = failures.iter().any(|failure| match failure {
+        VerifyFailure::ConstraintNotSatisfied {
+            constraint,
+            location,
+            ..
+        } => {
+            constraint.to_string().contains(gate_name)
+                && matches!(
+                    location,
+                    FailureLocation::InRegion { offset, .. } if *offset == row
+                )
+        }
+        _ => false,
+    });
+
+    assert!(
+        found,
+        "expected a ConstraintNotSatisfied failure for gate \"{gate_name}\" at row {row}, got: {failures:?}"
+    );
+}
+
+/// Asserts that `failures` contains a `CellNotAssigned` failure — the
+/// "unusable rows / missing selector" poisoned-cell case that MockProver
+/// reports when a region leaves a required cell unassigned.
+pub fn assert_cell_not_assigned(failures: &[VerifyFailure]) {
+    let found = failures
+        .iter()
+        .any(|failure| matches!(failure, VerifyFailure::CellNotAssigned { .. }));
+
+    assert!(
+        found,
+        "expected a CellNotAssigned failure, got: {failures:?}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        pasta::Fp,
+        plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Selector},
+        poly::Rotation,
+    };
+
+    // Enables a gate's selector for a row but never assigns the advice cell
+    // the gate reads at that row, which is exactly the "unusable rows /
+    // missing selector" poisoned-cell case MockProver reports as
+    // CellNotAssigned.
+    #[derive(Default)]
+    struct PoisonedCircuit;
+
+    #[derive(Clone)]
+    struct PoisonedConfig {
+        advice: Column<Advice>,
+        selector: Selector,
+    }
+
+    impl Circuit<Fp> for PoisonedCircuit {
+        type Config = PoisonedConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+            let advice = meta.advice_column();
+            let selector = meta.selector();
+
+            meta.create_gate("poisoned", |meta| {
+                let advice = meta.query_advice(advice, Rotation::cur());
+                let selector = meta.query_selector(selector);
+                vec![selector * advice]
+            });
+
+            PoisonedConfig { advice, selector }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            layouter.assign_region(
+                || "poisoned row",
+                |mut region| {
+                    // Enable the selector but never assign `advice` at this row.
+                    config.selector.enable(&mut region, 0)
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn test_assert_cell_not_assigned() {
+        let circuit = PoisonedCircuit;
+        let k = 4;
+        let failures = run_mock_prover(k, &circuit, vec![]);
+        assert_cell_not_assigned(&failures);
+    }
+}
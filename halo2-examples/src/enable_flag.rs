@@ -0,0 +1,308 @@
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::*,
+    dev::MockProver,
+    pasta::Fp,
+    plonk::*,
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+// Enforces: value == 0 OR enable_flag == 1.
+// This is the classic guard used to conditionally disable behavior: when
+// enable_flag is 1 the value is unconstrained, when enable_flag is 0 the
+// value must be zero.
+#[derive(Clone, Debug)]
+pub struct EnableFlagConfig {
+    pub q_enable: Selector,
+    pub value: Column<Advice>,
+    pub enable_flag: Column<Advice>,
+}
+
+pub struct EnableFlagChip<F: FieldExt> {
+    config: EnableFlagConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: FieldExt> EnableFlagChip<F> {
+    pub fn construct(config: EnableFlagConfig) -> Self {
+        EnableFlagChip {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        q_enable: Selector,
+        value: Column<Advice>,
+        enable_flag: Column<Advice>,
+    ) -> EnableFlagConfig {
+        meta.create_gate("enable_flag", |meta| {
+            //
+            // enable_flag | value | (1 - enable_flag) * value
+            // ------------+-------+---------------------------
+            //      1      |   x   |            0
+            //      0      |   0   |            0
+            //      0      |   x   |            x  <- rejected
+
+            let q_enable = meta.query_selector(q_enable);
+            let value = meta.query_advice(value, Rotation::cur());
+            let enable_flag = meta.query_advice(enable_flag, Rotation::cur());
+
+            vec![
+                // enable_flag must be boolean
+                q_enable.clone() * enable_flag.clone() * (Expression::Constant(F::one()) - enable_flag.clone()),
+                // value = 0 OR enable_flag = 1
+                q_enable * (Expression::Constant(F::one()) - enable_flag) * value,
+            ]
+        });
+
+        EnableFlagConfig {
+            q_enable,
+            value,
+            enable_flag,
+        }
+    }
+
+    pub fn assign(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        value: Value<F>,
+        enable_flag: Value<F>,
+    ) -> Result<(), Error> {
+        self.config.q_enable.enable(region, offset)?;
+        region.assign_advice(|| "value", self.config.value, offset, || value)?;
+        region.assign_advice(|| "enable_flag", self.config.enable_flag, offset, || enable_flag)?;
+        Ok(())
+    }
+
+    // Same as `assign`, but for when `value` and/or `enable_flag` already live
+    // in a prior cell (e.g. an `IsZeroChip` output) and must be wired in via a
+    // copy constraint instead of being re-witnessed from scratch.
+    pub fn assign_copy(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        value: AssignedCell<F, F>,
+        enable_flag: AssignedCell<F, F>,
+    ) -> Result<(), Error> {
+        self.config.q_enable.enable(region, offset)?;
+        value.copy_advice(|| "value", region, self.config.value, offset)?;
+        enable_flag.copy_advice(|| "enable_flag", region, self.config.enable_flag, offset)?;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct TestCircuit<F: FieldExt> {
+    pub values: Vec<Value<F>>,
+    pub enable_flags: Vec<Value<F>>,
+    _marker: PhantomData<F>,
+}
+
+#[derive(Clone, Debug)]
+struct TestConfig {
+    pub enable_flag_config: EnableFlagConfig,
+}
+
+impl<F: FieldExt> Circuit<F> for TestCircuit<F> {
+    type Config = TestConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let value = meta.advice_column();
+        let enable_flag = meta.advice_column();
+        let selector = meta.selector();
+
+        meta.enable_equality(value);
+        meta.enable_equality(enable_flag);
+
+        let enable_flag_config = EnableFlagChip::configure(meta, selector, value, enable_flag);
+
+        TestConfig { enable_flag_config }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let enable_flag_chip = EnableFlagChip::construct(config.enable_flag_config);
+
+        layouter.assign_region(
+            || "test enable_flag",
+            |mut region| {
+                for (i, (&value, &enable_flag)) in
+                    self.values.iter().zip(self.enable_flags.iter()).enumerate()
+                {
+                    enable_flag_chip.assign(&mut region, i, value, enable_flag)?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+// Exercises `EnableFlagChip::assign_copy` by wiring both inputs in from prior
+// cells: `value` is witnessed directly, and `enable_flag` is copied straight
+// out of an `IsZeroChip` output, with no re-witnessing in between.
+#[derive(Default)]
+struct CopyTestCircuit<F: FieldExt> {
+    pub control: Value<F>, // enable_flag = is_zero(control)
+    pub value: Value<F>,
+    _marker: PhantomData<F>,
+}
+
+#[derive(Clone, Debug)]
+struct CopyTestConfig<F: FieldExt> {
+    pub value_in: Column<Advice>,
+    pub is_zero_config: crate::is_zero::IsZeroConfig<F>,
+    pub enable_flag_config: EnableFlagConfig,
+}
+
+impl<F: FieldExt> Circuit<F> for CopyTestCircuit<F> {
+    type Config = CopyTestConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let value_in = meta.advice_column();
+        meta.enable_equality(value_in);
+
+        let control = meta.advice_column();
+        let control_inv = meta.advice_column();
+        let control_selector = meta.selector();
+        meta.enable_equality(control);
+        meta.enable_equality(control_inv);
+
+        let is_zero_config =
+            crate::is_zero::IsZeroChip::configure(meta, control_selector, control, control_inv);
+
+        let value = meta.advice_column();
+        let enable_flag = meta.advice_column();
+        let enable_flag_selector = meta.selector();
+        meta.enable_equality(value);
+        meta.enable_equality(enable_flag);
+
+        let enable_flag_config =
+            EnableFlagChip::configure(meta, enable_flag_selector, value, enable_flag);
+
+        CopyTestConfig {
+            value_in,
+            is_zero_config,
+            enable_flag_config,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        use crate::is_zero::{IsZeroChip, IsZeroInstructions};
+
+        let is_zero_chip = IsZeroChip::construct(config.is_zero_config);
+        let enable_flag_chip = EnableFlagChip::construct(config.enable_flag_config);
+
+        let control_cell = layouter.assign_region(
+            || "witness control",
+            |mut region| region.assign_advice(|| "control", config.value_in, 0, || self.control),
+        )?;
+
+        let enable_flag_cell =
+            is_zero_chip.is_zero(layouter.namespace(|| "is_zero"), control_cell)?;
+
+        let value_cell = layouter.assign_region(
+            || "witness value",
+            |mut region| region.assign_advice(|| "value", config.value_in, 0, || self.value),
+        )?;
+
+        layouter.assign_region(
+            || "enable_flag (copied)",
+            |mut region| {
+                enable_flag_chip.assign_copy(
+                    &mut region,
+                    0,
+                    value_cell.clone(),
+                    enable_flag_cell.clone(),
+                )
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enable_flag_passes_when_guard_satisfied() {
+        let circuit = TestCircuit {
+            values: vec![
+                Value::known(Fp::zero()),   // value = 0, flag = 0: ok
+                Value::known(Fp::from(42)), // value != 0, flag = 1: ok
+                Value::known(Fp::zero()),   // value = 0, flag = 1: ok
+            ],
+            enable_flags: vec![
+                Value::known(Fp::zero()),
+                Value::known(Fp::one()),
+                Value::known(Fp::one()),
+            ],
+            _marker: PhantomData,
+        };
+
+        let k = 4;
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_enable_flag_fails_when_value_nonzero_and_disabled() {
+        let circuit = TestCircuit {
+            values: vec![Value::known(Fp::from(7))],
+            enable_flags: vec![Value::known(Fp::zero())],
+            _marker: PhantomData,
+        };
+
+        let k = 4;
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_enable_flag_assign_copy_from_is_zero() {
+        // control = 0 => enable_flag = 1 => value is unconstrained
+        let circuit = CopyTestCircuit {
+            control: Value::known(Fp::zero()),
+            value: Value::known(Fp::from(99)),
+            _marker: PhantomData,
+        };
+
+        let k = 5;
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_enable_flag_assign_copy_rejects_nonzero_guard() {
+        // control != 0 => enable_flag = 0 => value must be zero, but isn't
+        let circuit = CopyTestCircuit {
+            control: Value::known(Fp::from(3)),
+            value: Value::known(Fp::from(99)),
+            _marker: PhantomData,
+        };
+
+        let k = 5;
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
@@ -0,0 +1,255 @@
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::*,
+    dev::MockProver,
+    pasta::Fp,
+    plonk::*,
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+#[derive(Clone, Debug)]
+pub struct IsEqualConfig<F> {
+    pub diff_inv: Column<Advice>, // diff invert = 1/(a - b)
+    pub is_equal_expr: Expression<F>, // if a = b, then is_equal_expr = 1, else is_equal_expr = 0
+    // We can use this is_equal_expr as a selector the same way IsZeroConfig::expr() is used!
+}
+
+impl<F: FieldExt> IsEqualConfig<F> {
+    pub fn expr(&self) -> Expression<F> {
+        self.is_equal_expr.clone()
+    }
+}
+
+pub struct IsEqualChip<F: FieldExt> {
+    config: IsEqualConfig<F>,
+}
+
+impl<F: FieldExt> IsEqualChip<F> {
+    pub fn construct(config: IsEqualConfig<F>) -> Self {
+        IsEqualChip { config }
+    }
+
+    // q_enable is a selector to enable the gate. q_enable is a closure
+    // a and b are the two expressions to be compared. They are closures
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        q_enable: impl FnOnce(&mut VirtualCells<'_, F>) -> Expression<F>,
+        a: impl FnOnce(&mut VirtualCells<'_, F>) -> Expression<F>,
+        b: impl FnOnce(&mut VirtualCells<'_, F>) -> Expression<F>,
+        diff_inv: Column<Advice>,
+    ) -> IsEqualConfig<F> {
+        let mut is_equal_expr = Expression::Constant(F::zero());
+
+        meta.create_gate("is_equal", |meta| {
+            //
+            // valid | diff  |  diff_inv  |  1 - diff * diff_inv | diff * (1 - diff * diff_inv)
+            // ------+-------+------------+-----------------------+------------------------------
+            //  yes  |   x   |    1/x     |         0             |  0
+            //  no   |   x   |    0       |         1             |  x
+            //  yes  |   0   |    0       |         1             |  0
+            //  yes  |   0   |    y       |         1             |  0
+
+            let a = a(meta);
+            let b = b(meta);
+            let q_enable = q_enable(meta);
+            // query diff_inv from the advice columns
+            let diff_inv = meta.query_advice(diff_inv, Rotation::cur());
+
+            let diff = a - b;
+
+            // This is the expression assignement for is_equal_expr
+            is_equal_expr = Expression::Constant(F::one()) - diff.clone() * diff_inv;
+
+            // Same forgery concern as is_zero: without this gate a malicious prover could
+            // set diff_inv = 0 and claim is_equal_expr = 1 even when a != b
+            vec![q_enable * diff * is_equal_expr.clone()]
+        });
+
+        IsEqualConfig {
+            diff_inv,
+            is_equal_expr,
+        }
+    }
+
+    // The assignment function takes a and b, computes diff = a - b, generates its inverse
+    // and assigns it to the advice column
+    pub fn assign(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        a: Value<F>,
+        b: Value<F>,
+    ) -> Result<(), Error> {
+        let diff_inv = (a - b).map(|diff| diff.invert().unwrap_or(F::zero()));
+        region.assign_advice(|| "diff inv", self.config.diff_inv, offset, || diff_inv)?;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct TestCircuit<F: FieldExt> {
+    pub a_values: Vec<Value<F>>,
+    pub b_values: Vec<Value<F>>,
+    _marker: PhantomData<F>,
+}
+
+#[derive(Clone, Debug)]
+struct TestConfig<F: FieldExt> {
+    pub a: Column<Advice>,
+    pub b: Column<Advice>,
+    pub selector: Selector,
+    pub is_equal_config: IsEqualConfig<F>,
+}
+
+impl<F: FieldExt> Circuit<F> for TestCircuit<F> {
+    type Config = TestConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let selector = meta.selector();
+        let diff_inv = meta.advice_column();
+
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+        meta.enable_equality(diff_inv);
+
+        let is_equal_config = IsEqualChip::configure(
+            meta,
+            |meta| meta.query_selector(selector), // q_enable
+            |meta| meta.query_advice(a, Rotation::cur()), // a
+            |meta| meta.query_advice(b, Rotation::cur()), // b
+            diff_inv,
+        );
+
+        TestConfig {
+            a,
+            b,
+            selector,
+            is_equal_config,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let is_equal_chip = IsEqualChip::construct(config.is_equal_config);
+
+        layouter.assign_region(
+            || "test is_equal",
+            |mut region| {
+                for (i, (&a_value, &b_value)) in
+                    self.a_values.iter().zip(self.b_values.iter()).enumerate()
+                {
+                    config.selector.enable(&mut region, i)?;
+
+                    region.assign_advice(|| "a", config.a, i, || a_value)?;
+                    region.assign_advice(|| "b", config.b, i, || b_value)?;
+
+                    is_equal_chip.assign(&mut region, i, a_value, b_value)?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_equal_with_various_values() {
+        let a_values = vec![
+            Value::known(Fp::zero()),
+            Value::known(Fp::one()),
+            Value::known(Fp::from(42)),
+            Value::known(-Fp::one()),
+            Value::known(Fp::from(100)),
+        ];
+        let b_values = vec![
+            Value::known(Fp::zero()),  // equal
+            Value::known(Fp::from(2)), // not equal
+            Value::known(Fp::from(42)), // equal
+            Value::known(Fp::one()),   // not equal
+            Value::known(Fp::from(100)), // equal
+        ];
+
+        let circuit = TestCircuit {
+            a_values,
+            b_values,
+            _marker: PhantomData,
+        };
+
+        let k = 4;
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    // Mirrors is_zero.rs's forged-inverse test: without the gate's
+    // `diff * is_equal_expr` constraint, a malicious prover could set
+    // diff_inv = 0 for a != b and claim is_equal_expr = 1 anyway.
+    #[test]
+    fn test_is_equal_should_fail_with_invalid_inverse() {
+        #[derive(Default)]
+        struct CheatCircuit<F: FieldExt> {
+            _marker: PhantomData<F>,
+        }
+
+        impl<F: FieldExt> Circuit<F> for CheatCircuit<F> {
+            type Config = TestConfig<F>;
+            type FloorPlanner = SimpleFloorPlanner;
+
+            fn without_witnesses(&self) -> Self {
+                Self::default()
+            }
+
+            fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+                TestCircuit::<F>::configure(meta)
+            }
+
+            fn synthesize(
+                &self,
+                config: Self::Config,
+                mut layouter: impl Layouter<F>,
+            ) -> Result<(), Error> {
+                layouter.assign_region(
+                    || "cheat test",
+                    |mut region| {
+                        config.selector.enable(&mut region, 0)?;
+
+                        // a != b, so diff = 5 - 2 = 3 is non-zero
+                        region.assign_advice(|| "a", config.a, 0, || Value::known(F::from(5)))?;
+                        region.assign_advice(|| "b", config.b, 0, || Value::known(F::from(2)))?;
+
+                        // But claim diff_inv = 0, which forces is_equal_expr = 1
+                        region.assign_advice(
+                            || "diff_inv",
+                            config.is_equal_config.diff_inv,
+                            0,
+                            || Value::known(F::zero()),
+                        )?;
+
+                        Ok(())
+                    },
+                )
+            }
+        }
+
+        let cheat_circuit = CheatCircuit::<Fp> {
+            _marker: PhantomData,
+        };
+        let k = 4;
+        let failures = crate::dev::run_mock_prover(k, &cheat_circuit, vec![]);
+        crate::dev::assert_constraint_fails(&failures, "is_equal", 0);
+    }
+}
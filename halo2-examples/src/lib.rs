@@ -0,0 +1,5 @@
+pub mod dev;
+pub mod enable_flag;
+pub mod is_equal;
+pub mod is_zero;
+pub mod mux;
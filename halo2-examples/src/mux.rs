@@ -0,0 +1,300 @@
+use crate::is_zero::{IsZeroChip, IsZeroInstructions};
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::*,
+    dev::MockProver,
+    pasta::Fp,
+    plonk::*,
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+// Conditional-select (mux): out = choice * right + (1 - choice) * left.
+// `choice` is typically the boolean output of an IsZeroChip, wired in through
+// a copy constraint, so "if value is zero pick right, else pick left" can be
+// expressed end to end.
+#[derive(Clone, Debug)]
+pub struct MuxConfig {
+    pub q_enable: Selector,
+    pub choice: Column<Advice>,
+    pub left: Column<Advice>,
+    pub right: Column<Advice>,
+    pub out: Column<Advice>,
+}
+
+pub struct MuxChip<F: FieldExt> {
+    config: MuxConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: FieldExt> MuxChip<F> {
+    pub fn construct(config: MuxConfig) -> Self {
+        MuxChip {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        q_enable: Selector,
+        choice: Column<Advice>,
+        left: Column<Advice>,
+        right: Column<Advice>,
+        out: Column<Advice>,
+    ) -> MuxConfig {
+        meta.enable_equality(choice);
+        meta.enable_equality(left);
+        meta.enable_equality(right);
+        meta.enable_equality(out);
+
+        meta.create_gate("mux", |meta| {
+            let q_enable = meta.query_selector(q_enable);
+            let choice = meta.query_advice(choice, Rotation::cur());
+            let left = meta.query_advice(left, Rotation::cur());
+            let right = meta.query_advice(right, Rotation::cur());
+            let out = meta.query_advice(out, Rotation::cur());
+
+            let one = Expression::Constant(F::one());
+
+            vec![
+                // choice must be boolean
+                q_enable.clone() * choice.clone() * (one.clone() - choice.clone()),
+                // out = choice * right + (1 - choice) * left
+                q_enable
+                    * (out - (choice.clone() * right + (one - choice) * left)),
+            ]
+        });
+
+        MuxConfig {
+            q_enable,
+            choice,
+            left,
+            right,
+            out,
+        }
+    }
+
+    pub fn assign(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        choice: AssignedCell<F, F>,
+        left: Value<F>,
+        right: Value<F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        self.config.q_enable.enable(region, offset)?;
+
+        choice.copy_advice(|| "choice", region, self.config.choice, offset)?;
+        region.assign_advice(|| "left", self.config.left, offset, || left)?;
+        region.assign_advice(|| "right", self.config.right, offset, || right)?;
+
+        let choice_value = choice.value().copied();
+        let out = choice_value
+            .zip(left)
+            .zip(right)
+            .map(|((choice, left), right)| {
+                choice * right + (F::one() - choice) * left
+            });
+        region.assign_advice(|| "out", self.config.out, offset, || out)
+    }
+}
+
+#[derive(Default)]
+struct TestCircuit<F: FieldExt> {
+    // If `test_value` is zero, `right` should be selected, otherwise `left`.
+    pub test_value: Value<F>,
+    pub left: Value<F>,
+    pub right: Value<F>,
+    _marker: PhantomData<F>,
+}
+
+#[derive(Clone, Debug)]
+struct TestConfig<F: FieldExt> {
+    pub value_in: Column<Advice>,
+    pub is_zero_config: crate::is_zero::IsZeroConfig<F>,
+    pub mux_config: MuxConfig,
+}
+
+impl<F: FieldExt> Circuit<F> for TestCircuit<F> {
+    type Config = TestConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let value_in = meta.advice_column();
+        meta.enable_equality(value_in);
+
+        let value = meta.advice_column();
+        let value_inv = meta.advice_column();
+        let value_selector = meta.selector();
+        meta.enable_equality(value);
+        meta.enable_equality(value_inv);
+
+        let is_zero_config = IsZeroChip::configure(meta, value_selector, value, value_inv);
+
+        let choice = meta.advice_column();
+        let left = meta.advice_column();
+        let right = meta.advice_column();
+        let out = meta.advice_column();
+        let mux_selector = meta.selector();
+
+        let mux_config = MuxChip::configure(meta, mux_selector, choice, left, right, out);
+
+        TestConfig {
+            value_in,
+            is_zero_config,
+            mux_config,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let is_zero_chip = IsZeroChip::construct(config.is_zero_config);
+        let mux_chip = MuxChip::construct(config.mux_config);
+
+        let value_cell = layouter.assign_region(
+            || "witness value",
+            |mut region| {
+                region.assign_advice(|| "value", config.value_in, 0, || self.test_value)
+            },
+        )?;
+
+        let choice = is_zero_chip.is_zero(layouter.namespace(|| "is_zero"), value_cell)?;
+
+        layouter.assign_region(
+            || "mux",
+            |mut region| mux_chip.assign(&mut region, 0, choice.clone(), self.left, self.right),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mux_picks_right_when_value_is_zero() {
+        let circuit = TestCircuit {
+            test_value: Value::known(Fp::zero()),
+            left: Value::known(Fp::from(10)),
+            right: Value::known(Fp::from(20)),
+            _marker: PhantomData,
+        };
+
+        let k = 4;
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_mux_picks_left_when_value_is_nonzero() {
+        let circuit = TestCircuit {
+            test_value: Value::known(Fp::from(7)),
+            left: Value::known(Fp::from(10)),
+            right: Value::known(Fp::from(20)),
+            _marker: PhantomData,
+        };
+
+        let k = 4;
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    // Minimal circuit exercising MuxChip in isolation (no IsZeroChip), so
+    // negative tests can forge witnesses directly without going through a
+    // copy constraint.
+    #[derive(Default)]
+    struct CheatCircuit<F: FieldExt> {
+        choice: Value<F>,
+        left: Value<F>,
+        right: Value<F>,
+        out: Value<F>,
+        _marker: PhantomData<F>,
+    }
+
+    #[derive(Clone, Debug)]
+    struct CheatConfig {
+        mux_config: MuxConfig,
+    }
+
+    impl<F: FieldExt> Circuit<F> for CheatCircuit<F> {
+        type Config = CheatConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let choice = meta.advice_column();
+            let left = meta.advice_column();
+            let right = meta.advice_column();
+            let out = meta.advice_column();
+            let selector = meta.selector();
+
+            let mux_config = MuxChip::configure(meta, selector, choice, left, right, out);
+
+            CheatConfig { mux_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            layouter.assign_region(
+                || "cheat mux",
+                |mut region| {
+                    config.mux_config.q_enable.enable(&mut region, 0)?;
+                    region.assign_advice(|| "choice", config.mux_config.choice, 0, || self.choice)?;
+                    region.assign_advice(|| "left", config.mux_config.left, 0, || self.left)?;
+                    region.assign_advice(|| "right", config.mux_config.right, 0, || self.right)?;
+                    region.assign_advice(|| "out", config.mux_config.out, 0, || self.out)?;
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn test_mux_rejects_forged_out() {
+        // choice = 0 should select left (10), but the witness claims out = 999.
+        let cheat_circuit = CheatCircuit::<Fp> {
+            choice: Value::known(Fp::zero()),
+            left: Value::known(Fp::from(10)),
+            right: Value::known(Fp::from(20)),
+            out: Value::known(Fp::from(999)),
+            _marker: PhantomData,
+        };
+
+        let k = 4;
+        let failures = crate::dev::run_mock_prover(k, &cheat_circuit, vec![]);
+        crate::dev::assert_constraint_fails(&failures, "mux", 0);
+    }
+
+    #[test]
+    fn test_mux_rejects_non_boolean_choice() {
+        // choice = 2 is out of range; the boolean check in the mux gate must reject it.
+        let cheat_circuit = CheatCircuit::<Fp> {
+            choice: Value::known(Fp::from(2)),
+            left: Value::known(Fp::from(10)),
+            right: Value::known(Fp::from(20)),
+            // out = choice * right + (1 - choice) * left, honestly computed for choice = 2
+            out: Value::known(Fp::from(2) * Fp::from(20) - Fp::from(10)),
+            _marker: PhantomData,
+        };
+
+        let k = 4;
+        let failures = crate::dev::run_mock_prover(k, &cheat_circuit, vec![]);
+        crate::dev::assert_constraint_fails(&failures, "mux", 0);
+    }
+}